@@ -1,30 +1,32 @@
+use std::collections::{HashMap, HashSet};
 use std::io::{self, Write};
-use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
-use std::sync::Arc;
+use std::net::Shutdown;
+use std::os::unix::net::{UnixListener, UnixStream};
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Condvar, Mutex};
 use std::thread;
 use std::time::{Duration, Instant};
+use serde::{Deserialize, Serialize};
 use termion::event::Key;
 use termion::input::TermRead;
 use termion::raw::IntoRawMode;
+use termion::screen::IntoAlternateScreen;
 use termion::{clear, color, cursor};
 
 #[derive(Debug, Clone)]
 struct TimerConfig {
     default_break_interval: u64,
-    min_break_interval: u64,
-    interval_change: u64,
-    max_reminders: usize,
-    reminder_interval: u64,
+    num_slots: usize,
+    tick_ms: u64,
 }
 
 impl Default for TimerConfig {
     fn default() -> Self {
         Self {
             default_break_interval: 50 * 60,
-            min_break_interval: 5 * 60,
-            interval_change: 5 * 60,
-            max_reminders: 8,
-            reminder_interval: 5 * 60,
+            num_slots: 64,
+            tick_ms: 1000,
         }
     }
 }
@@ -33,170 +35,817 @@ fn format_time(seconds: u64) -> String {
     format!("{:02}:{:02}", seconds / 60, seconds % 60)
 }
 
-/// A configurable timer that manages break intervals and reminders
-///
-/// Handles thread-safe state management and user interactions
-struct Timer {
-    break_interval: Arc<AtomicU64>,
-    next_break_time: Arc<AtomicU64>,
-    reminder_count: Arc<AtomicUsize>,
-    should_exit: Arc<AtomicBool>,
-    is_break_time: Arc<AtomicBool>,
-    config: TimerConfig,
+/// Work/break lengths and how many work cycles pass before a long break
+#[derive(Debug, Clone)]
+struct PomodoroConfig {
+    work: u64,
+    short_break: u64,
+    long_break: u64,
+    cycles_before_long: u64,
 }
 
-impl Timer {
-    /// Handle interval change requests from user input
-    fn handle_interval_change(
-        &self,
-        key: Key,
-        stdout: &mut termion::raw::RawTerminal<io::Stdout>,
-    ) -> io::Result<()> {
-        if !self.is_break_time.load(Ordering::SeqCst) {
-            let current_interval = self.break_interval.load(Ordering::SeqCst);
-            let (new_interval, action) = if key == Key::Char('+') {
-                (current_interval + self.config.interval_change, "increased")
+impl Default for PomodoroConfig {
+    fn default() -> Self {
+        Self {
+            work: 25 * 60,
+            short_break: 5 * 60,
+            long_break: 15 * 60,
+            cycles_before_long: 4,
+        }
+    }
+}
+
+/// Which leg of the work/break cycle a running Pomodoro session is in
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+enum Phase {
+    Work,
+    ShortBreak,
+    LongBreak,
+}
+
+impl Phase {
+    fn label(&self) -> &'static str {
+        match self {
+            Phase::Work => "Work",
+            Phase::ShortBreak => "Short break",
+            Phase::LongBreak => "Long break",
+        }
+    }
+}
+
+/// A Pomodoro session layered on top of the break-reminder loop: work and
+/// break phases alternate, independent of the named timers tracked by the
+/// `Scheduler`. Remaining time is a plain countdown of seconds decremented
+/// once per call to `tick`, the same way the `Scheduler`'s wheel only ever
+/// advances via an explicit `tick` call -- so, like the named timers, a
+/// Pomodoro phase freezes for free whenever the timer thread stops calling
+/// `tick` while paused, with no separate pause bookkeeping needed here
+struct PomodoroState {
+    config: PomodoroConfig,
+    running: AtomicBool,
+    phase: Mutex<Phase>,
+    completed_work_cycles: AtomicU64,
+    remaining_secs: AtomicU64,
+}
+
+impl PomodoroState {
+    fn new(config: PomodoroConfig) -> Self {
+        Self {
+            remaining_secs: AtomicU64::new(0),
+            config,
+            running: AtomicBool::new(false),
+            phase: Mutex::new(Phase::Work),
+            completed_work_cycles: AtomicU64::new(0),
+        }
+    }
+
+    fn phase_secs(&self, phase: Phase) -> u64 {
+        match phase {
+            Phase::Work => self.config.work,
+            Phase::ShortBreak => self.config.short_break,
+            Phase::LongBreak => self.config.long_break,
+        }
+    }
+
+    /// Begin a fresh session at "Work 1/N"
+    fn start(&self) -> String {
+        self.running.store(true, Ordering::SeqCst);
+        self.completed_work_cycles.store(0, Ordering::SeqCst);
+        *self.phase.lock().unwrap() = Phase::Work;
+        self.remaining_secs.store(self.phase_secs(Phase::Work), Ordering::SeqCst);
+        format!("Pomodoro started: {}", self.status_text())
+    }
+
+    /// Zero the completed-cycle counter and return to "Work 1/N" without
+    /// stopping a session that is already running
+    fn reset(&self) -> String {
+        self.completed_work_cycles.store(0, Ordering::SeqCst);
+        *self.phase.lock().unwrap() = Phase::Work;
+        self.remaining_secs.store(self.phase_secs(Phase::Work), Ordering::SeqCst);
+        format!("Pomodoro reset: {}", self.status_text())
+    }
+
+    /// Move to the next phase immediately, as if the current one had
+    /// just timed out
+    fn skip(&self) -> String {
+        if !self.running.load(Ordering::SeqCst) {
+            return "Pomodoro is not running".to_string();
+        }
+        self.advance()
+    }
+
+    /// Advance Work -> (Short|Long)Break -> Work, bumping the completed-work
+    /// counter whenever a Work phase ends and escalating to a long break
+    /// every `cycles_before_long` completed cycles
+    fn advance(&self) -> String {
+        let mut phase = self.phase.lock().unwrap();
+        let next = match *phase {
+            Phase::Work => {
+                let completed = self.completed_work_cycles.fetch_add(1, Ordering::SeqCst) + 1;
+                if completed.is_multiple_of(self.config.cycles_before_long) {
+                    Phase::LongBreak
+                } else {
+                    Phase::ShortBreak
+                }
+            }
+            Phase::ShortBreak | Phase::LongBreak => Phase::Work,
+        };
+        *phase = next;
+        self.remaining_secs.store(self.phase_secs(next), Ordering::SeqCst);
+        drop(phase);
+        format!("Time to {}! {}", next.label().to_lowercase(), self.status_text())
+    }
+
+    /// Count the current phase down by `tick_secs`, advancing (and
+    /// returning the transition message to announce) once it runs out.
+    /// Called only while running and not paused, so pausing simply means
+    /// this never gets called and `remaining_secs` holds still
+    fn tick(&self, tick_secs: u64) -> Option<String> {
+        if !self.running.load(Ordering::SeqCst) {
+            return None;
+        }
+        let remaining = self.remaining_secs.load(Ordering::SeqCst);
+        if remaining > tick_secs {
+            self.remaining_secs.store(remaining - tick_secs, Ordering::SeqCst);
+            None
+        } else {
+            Some(self.advance())
+        }
+    }
+
+    /// e.g. "Work 3/4 (12:34 left)". Work shows the cycle in progress;
+    /// the breaks that follow show the cycle that just finished, so a
+    /// "Work 1/4" is always followed by "Short break 1/4", not "2/4"
+    fn status_text(&self) -> String {
+        let phase = *self.phase.lock().unwrap();
+        let completed = self.completed_work_cycles.load(Ordering::SeqCst);
+        let cycle = match phase {
+            Phase::Work => completed % self.config.cycles_before_long + 1,
+            Phase::ShortBreak | Phase::LongBreak => {
+                let finished = completed % self.config.cycles_before_long;
+                if finished == 0 {
+                    self.config.cycles_before_long
+                } else {
+                    finished
+                }
+            }
+        };
+        let remaining = self.remaining_secs.load(Ordering::SeqCst);
+        format!(
+            "{} {}/{} ({} left)",
+            phase.label(),
+            cycle,
+            self.config.cycles_before_long,
+            format_time(remaining)
+        )
+    }
+
+    fn status_line(&self) -> Option<String> {
+        self.running
+            .load(Ordering::SeqCst)
+            .then(|| format!("Pomodoro: {}", self.status_text()))
+    }
+}
+
+/// Path of the Unix socket the daemon listens on and clients connect to
+fn socket_path() -> PathBuf {
+    std::env::var("RUSTY_SOCKET")
+        .map(PathBuf::from)
+        .unwrap_or_else(|_| PathBuf::from("/tmp/rusty.sock"))
+}
+
+/// Commands the timer can be asked to carry out, independent of whether
+/// they came from the keyboard or over the daemon's Unix socket
+#[derive(Debug, Clone, Serialize, Deserialize)]
+enum Command {
+    Add { name: String, interval_secs: u64 },
+    Remove { name: String },
+    Toggle { name: String },
+    Pause,
+    Resume,
+    SetInterval { name: String, interval_secs: u64 },
+    List,
+    PomodoroStart,
+    PomodoroSkip,
+    PomodoroReset,
+    Quit,
+}
+
+/// A named timer's remaining time and interval, as reported by `Command::List`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct TimerStatus {
+    name: String,
+    interval_secs: u64,
+    remaining_secs: u64,
+    enabled: bool,
+}
+
+/// Reply to a `Command`, carried back over the same channel it arrived on
+#[derive(Debug, Clone, Serialize, Deserialize)]
+enum Answer {
+    Ok(String),
+    Timers(Vec<TimerStatus>),
+    Error(String),
+}
+
+/// Render an `Answer` the way a human front-end should present it
+fn describe(answer: &Answer) -> String {
+    match answer {
+        Answer::Ok(message) => message.clone(),
+        Answer::Error(message) => format!("Error: {}", message),
+        Answer::Timers(timers) => {
+            if timers.is_empty() {
+                "No timers".to_string()
             } else {
-                let new_interval = (current_interval - self.config.interval_change)
-                    .max(self.config.min_break_interval);
-                (
-                    new_interval,
-                    if new_interval < current_interval {
-                        "decreased"
-                    } else {
-                        "already at minimum"
-                    },
-                )
-            };
+                timers
+                    .iter()
+                    .map(|timer| {
+                        format!(
+                            "{}: {} (every {}){}",
+                            timer.name,
+                            format_time(timer.remaining_secs),
+                            format_time(timer.interval_secs),
+                            if timer.enabled { "" } else { " [disabled]" }
+                        )
+                    })
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            }
+        }
+    }
+}
+
+/// A timer's position on the wheel: which slot it lives in is implicit in
+/// where the entry is stored, so all an entry needs to carry is how many
+/// full sweeps of the wheel are still left before it is due
+struct WheelEntry {
+    name: String,
+    interval_ticks: u64,
+    rotations: u64,
+}
+
+/// Hashed timing wheel scheduler: `num_slots` buckets (a power of two) that
+/// a background tick advances through one at a time. Inserting, removing or
+/// rescheduling a timer is O(1) regardless of how many timers are active,
+/// because it only ever touches the one slot the timer falls into; intervals
+/// longer than a single sweep are handled by `rotations`, decremented once
+/// per lap until they reach zero.
+struct Scheduler {
+    slots: Vec<Mutex<Vec<WheelEntry>>>,
+    mask: u64,
+    current_tick: AtomicU64,
+}
+
+impl Scheduler {
+    fn new(num_slots: usize) -> Self {
+        assert!(num_slots.is_power_of_two(), "num_slots must be a power of two");
+        Self {
+            slots: (0..num_slots).map(|_| Mutex::new(Vec::new())).collect(),
+            mask: (num_slots - 1) as u64,
+            current_tick: AtomicU64::new(0),
+        }
+    }
+
+    fn num_slots(&self) -> u64 {
+        self.mask + 1
+    }
+
+    fn log2_slots(&self) -> u32 {
+        self.num_slots().trailing_zeros()
+    }
 
-            self.break_interval.store(new_interval, Ordering::SeqCst);
-            self.next_break_time.store(new_interval, Ordering::SeqCst);
+    /// Place `name` `interval_ticks` ticks from now
+    fn insert(&self, name: String, interval_ticks: u64) {
+        let current = self.current_tick.load(Ordering::SeqCst);
+        let slot = ((current + interval_ticks) & self.mask) as usize;
+        let rotations = interval_ticks >> self.log2_slots();
+        self.slots[slot]
+            .lock()
+            .unwrap()
+            .push(WheelEntry { name, interval_ticks, rotations });
+    }
 
-            let message = format!("Break interval {} to {}", action, format_time(new_interval));
-            write!(stdout, "\r{}{}", clear::CurrentLine, message)?;
-            stdout.flush()?;
+    /// Drop every entry scheduled under `name`
+    fn remove(&self, name: &str) {
+        for slot in &self.slots {
+            slot.lock().unwrap().retain(|entry| entry.name != name);
         }
-        Ok(())
     }
 
-    /// Create a new Timer with the specified configuration
+    /// Ticks remaining until `name` next fires, if it is currently scheduled
+    fn remaining_ticks(&self, name: &str) -> Option<u64> {
+        let current = self.current_tick.load(Ordering::SeqCst);
+        for (slot_index, slot) in self.slots.iter().enumerate() {
+            let guard = slot.lock().unwrap();
+            if let Some(entry) = guard.iter().find(|entry| entry.name == name) {
+                let slot_distance = (slot_index as u64 + self.num_slots()
+                    - (current & self.mask))
+                    % self.num_slots();
+                return Some(entry.rotations * self.num_slots() + slot_distance);
+            }
+        }
+        None
+    }
+
+    /// Advance one tick, firing and re-inserting (for recurrence) every entry
+    /// whose rotation count has reached zero, and return their names
+    fn tick(&self) -> Vec<String> {
+        let current = self.current_tick.fetch_add(1, Ordering::SeqCst) + 1;
+        let slot_index = (current & self.mask) as usize;
+        let entries = std::mem::take(&mut *self.slots[slot_index].lock().unwrap());
+
+        let mut fired = Vec::new();
+        for entry in entries {
+            if entry.rotations == 0 {
+                fired.push(entry.name.clone());
+                self.insert(entry.name, entry.interval_ticks);
+            } else {
+                self.slots[slot_index].lock().unwrap().push(WheelEntry {
+                    rotations: entry.rotations - 1,
+                    ..entry
+                });
+            }
+        }
+        fired
+    }
+}
+
+/// Display metadata for a named timer, kept alongside the scheduler so the
+/// status line can be rendered without reaching into wheel internals
+struct TimerMeta {
+    interval_secs: u64,
+}
+
+/// Manages a set of independent named break timers and reminders, and is
+/// the single piece of shared state every front-end (keyboard, socket) acts
+/// through
+struct Timer {
+    scheduler: Arc<Scheduler>,
+    timers: Arc<Mutex<HashMap<String, TimerMeta>>>,
+    disabled: Arc<Mutex<HashSet<String>>>,
+    should_exit: Arc<AtomicBool>,
+    is_paused: Arc<AtomicBool>,
+    /// Notified whenever a `Command` mutates shared state, so the timer
+    /// thread can wake immediately instead of waiting out its current sleep
+    wake: Arc<(Mutex<()>, Condvar)>,
+    pomodoro: Arc<PomodoroState>,
+    config: TimerConfig,
+}
+
+impl Timer {
+    /// Create a new Timer with the specified configuration, seeded with a
+    /// single default "break" timer
     fn new(config: TimerConfig) -> Self {
-        Self {
-            break_interval: Arc::new(AtomicU64::new(config.default_break_interval)),
-            next_break_time: Arc::new(AtomicU64::new(config.default_break_interval)),
-            reminder_count: Arc::new(AtomicUsize::new(0)),
+        let timer = Self {
+            scheduler: Arc::new(Scheduler::new(config.num_slots)),
+            timers: Arc::new(Mutex::new(HashMap::new())),
+            disabled: Arc::new(Mutex::new(HashSet::new())),
             should_exit: Arc::new(AtomicBool::new(false)),
-            is_break_time: Arc::new(AtomicBool::new(false)),
+            is_paused: Arc::new(AtomicBool::new(false)),
+            wake: Arc::new((Mutex::new(()), Condvar::new())),
+            pomodoro: Arc::new(PomodoroState::new(PomodoroConfig::default())),
             config,
+        };
+        timer.add_timer("break".to_string(), timer.config.default_break_interval);
+        timer
+    }
+
+    /// Schedule a named timer to fire (and keep recurring) every
+    /// `interval_secs` seconds, replacing any existing schedule under the
+    /// same name rather than running both side by side
+    fn add_timer(&self, name: String, interval_secs: u64) {
+        let interval_ticks = (interval_secs * 1000 / self.config.tick_ms).max(1);
+        self.scheduler.remove(&name);
+        self.scheduler.insert(name.clone(), interval_ticks);
+        self.timers
+            .lock()
+            .unwrap()
+            .insert(name, TimerMeta { interval_secs });
+    }
+
+    /// Stop and forget a named timer
+    fn remove_timer(&self, name: &str) {
+        self.scheduler.remove(name);
+        self.timers.lock().unwrap().remove(name);
+        self.disabled.lock().unwrap().remove(name);
+    }
+
+    fn list_timers(&self) -> Vec<TimerStatus> {
+        let timers = self.timers.lock().unwrap();
+        let disabled = self.disabled.lock().unwrap();
+        let mut names: Vec<&String> = timers.keys().collect();
+        names.sort();
+
+        names
+            .into_iter()
+            .map(|name| TimerStatus {
+                name: name.clone(),
+                interval_secs: timers[name].interval_secs,
+                remaining_secs: self.scheduler.remaining_ticks(name).unwrap_or(0)
+                    * self.config.tick_ms
+                    / 1000,
+                enabled: !disabled.contains(name),
+            })
+            .collect()
+    }
+
+    /// Apply a `Command`, mutating shared state and returning the `Answer`
+    /// to report back to whichever front-end issued it
+    fn apply(&self, command: Command) -> Answer {
+        let answer = self.apply_inner(command);
+        // Wake the timer thread immediately so it notices the change on its
+        // next loop iteration instead of waiting out its current sleep
+        self.wake.1.notify_all();
+        answer
+    }
+
+    fn apply_inner(&self, command: Command) -> Answer {
+        match command {
+            Command::Add { name, interval_secs } => {
+                self.add_timer(name.clone(), interval_secs);
+                Answer::Ok(format!(
+                    "Added timer '{}' every {}",
+                    name,
+                    format_time(interval_secs)
+                ))
+            }
+            Command::Remove { name } => {
+                if self.timers.lock().unwrap().remove(&name).is_none() {
+                    return Answer::Error(format!("No such timer '{}'", name));
+                }
+                self.remove_timer(&name);
+                Answer::Ok(format!("Removed timer '{}'", name))
+            }
+            Command::Toggle { name } => {
+                if !self.timers.lock().unwrap().contains_key(&name) {
+                    return Answer::Error(format!("No such timer '{}'", name));
+                }
+                let mut disabled = self.disabled.lock().unwrap();
+                let now_disabled = !disabled.remove(&name);
+                if now_disabled {
+                    disabled.insert(name.clone());
+                }
+                Answer::Ok(format!(
+                    "Timer '{}' {}",
+                    name,
+                    if now_disabled { "disabled" } else { "enabled" }
+                ))
+            }
+            Command::Pause => {
+                self.is_paused.store(true, Ordering::SeqCst);
+                Answer::Ok("Paused".to_string())
+            }
+            Command::Resume => {
+                self.is_paused.store(false, Ordering::SeqCst);
+                Answer::Ok("Resumed".to_string())
+            }
+            Command::SetInterval { name, interval_secs } => {
+                if !self.timers.lock().unwrap().contains_key(&name) {
+                    return Answer::Error(format!("No such timer '{}'", name));
+                }
+                // add_timer replaces `name`'s existing wheel entry, so this
+                // reschedules the timer in place instead of adding a second,
+                // independently recurring one under the same name
+                self.add_timer(name.clone(), interval_secs);
+                Answer::Ok(format!(
+                    "Timer '{}' now every {}",
+                    name,
+                    format_time(interval_secs)
+                ))
+            }
+            Command::List => Answer::Timers(self.list_timers()),
+            Command::PomodoroStart => Answer::Ok(self.pomodoro.start()),
+            Command::PomodoroSkip => Answer::Ok(self.pomodoro.skip()),
+            Command::PomodoroReset => Answer::Ok(self.pomodoro.reset()),
+            Command::Quit => {
+                self.should_exit.store(true, Ordering::SeqCst);
+                Answer::Ok("Quitting".to_string())
+            }
         }
     }
 
-    /// Starts the timer thread that manages break reminders and countdown display
+    /// Starts the timer thread that advances the scheduler and renders the
+    /// multi-timer status display
     ///
     /// Returns a thread handle that should be joined during cleanup
     fn start_timer_thread(&self) -> thread::JoinHandle<()> {
-        let next_break_time = Arc::clone(&self.next_break_time);
-        let reminder_count = Arc::clone(&self.reminder_count);
+        let scheduler = Arc::clone(&self.scheduler);
         let should_exit = Arc::clone(&self.should_exit);
-        let is_break_time = Arc::clone(&self.is_break_time);
-        let config = self.config.clone();
+        let is_paused = Arc::clone(&self.is_paused);
+        let disabled = Arc::clone(&self.disabled);
+        let wake = Arc::clone(&self.wake);
+        let pomodoro = Arc::clone(&self.pomodoro);
+        let tick_ms = self.config.tick_ms;
+
+        let render_status = {
+            let timers = Arc::clone(&self.timers);
+            let scheduler = Arc::clone(&self.scheduler);
+            let disabled = Arc::clone(&self.disabled);
+            let pomodoro = Arc::clone(&self.pomodoro);
+            let tick_ms = self.config.tick_ms;
+            move || -> Vec<String> {
+                let timers = timers.lock().unwrap();
+                let disabled = disabled.lock().unwrap();
+                let mut names: Vec<&String> = timers.keys().collect();
+                names.sort();
+                let mut lines: Vec<String> = names
+                    .into_iter()
+                    .map(|name| {
+                        let remaining_ticks = scheduler.remaining_ticks(name).unwrap_or(0);
+                        let remaining_secs = remaining_ticks * tick_ms / 1000;
+                        let interval_secs = timers[name].interval_secs;
+                        format!(
+                            "{}: {} (every {}){}",
+                            name,
+                            format_time(remaining_secs),
+                            format_time(interval_secs),
+                            if disabled.contains(name) { " [disabled]" } else { "" }
+                        )
+                    })
+                    .collect();
+                if let Some(pomodoro_line) = pomodoro.status_line() {
+                    lines.push(pomodoro_line);
+                }
+                lines
+            }
+        };
 
         thread::spawn(move || {
-            let start_time = Instant::now();
+            let mut last_line_count: u16 = 0;
+
+            let redraw = |lines: &[String], last_line_count: &mut u16| {
+                if *last_line_count > 0 {
+                    print!("{}", cursor::Up(*last_line_count));
+                }
+                for line in lines {
+                    print!("\r{}{}\n", clear::CurrentLine, line);
+                }
+                io::stdout().flush().unwrap();
+                *last_line_count = lines.len() as u16;
+            };
+
+            let tick_duration = Duration::from_millis(tick_ms);
+            let mut deadline = Instant::now() + tick_duration;
+
+            // Sleep for up to the time left until `deadline`, but return as
+            // soon as a `Command` notifies `wake` so the outer loop can
+            // redraw (or notice a pause/resume) right away instead of lagging
+            // behind the next tick. A single `wait_timeout` call is enough:
+            // the outer loop only ever fires a tick once it re-checks that
+            // `deadline` has actually passed, so an early return here from a
+            // notify (or a spurious OS wakeup) can never cause a tick to
+            // fire ahead of schedule
+            let wait_until = |deadline: Instant| {
+                let (lock, condvar) = &*wake;
+                let guard = lock.lock().unwrap();
+                let timeout = deadline.saturating_duration_since(Instant::now());
+                let _ = condvar.wait_timeout(guard, timeout).unwrap();
+            };
+
             while !should_exit.load(Ordering::SeqCst) {
-                let elapsed = start_time.elapsed().as_secs();
-                let next_break = next_break_time.load(Ordering::SeqCst);
-                let reminders = reminder_count.load(Ordering::SeqCst);
-
-                if elapsed >= next_break {
-                    is_break_time.store(true, Ordering::SeqCst);
-                    if reminders < config.max_reminders {
-                        println!("\r{}Time to take a break!", clear::CurrentLine);
-                        io::stdout().flush().unwrap();
-                        reminder_count.fetch_add(1, Ordering::SeqCst);
-                        next_break_time
-                            .store(next_break + config.reminder_interval, Ordering::SeqCst);
-                    } else {
-                        break;
+                if is_paused.load(Ordering::SeqCst) {
+                    redraw(&["Paused".to_string()], &mut last_line_count);
+                    wait_until(Instant::now() + tick_duration);
+                    deadline = Instant::now() + tick_duration;
+                    continue;
+                }
+
+                if Instant::now() >= deadline {
+                    let fired = scheduler.tick();
+                    let disabled_now = disabled.lock().unwrap();
+                    for name in &fired {
+                        if !disabled_now.contains(name) {
+                            // Each announcement prints its own line below the
+                            // in-place status block, so the next redraw's
+                            // cursor-up needs to account for it too, or the
+                            // block undershoots and starts scrolling
+                            println!("\r{}Time for a {} break!", clear::CurrentLine, name);
+                            last_line_count += 1;
+                        }
+                    }
+                    drop(disabled_now);
+                    if let Some(message) = pomodoro.tick(tick_ms / 1000) {
+                        println!("\r{}{}", clear::CurrentLine, message);
+                        last_line_count += 1;
+                    }
+                    // Advance by a fixed increment rather than resetting from
+                    // `now`, so repeated ticks don't accumulate drift
+                    deadline += tick_duration;
+                    if deadline < Instant::now() {
+                        deadline = Instant::now() + tick_duration;
                     }
-                } else if !is_break_time.load(Ordering::SeqCst) {
-                    let remaining = next_break - elapsed;
-                    print!(
-                        "\r{}Time until next break: {}",
-                        clear::CurrentLine,
-                        format_time(remaining)
-                    );
-                    io::stdout().flush().unwrap();
                 }
 
-                let sleep_duration = if elapsed >= next_break {
-                    config.reminder_interval
-                } else {
-                    next_break - elapsed
-                };
-                thread::sleep(Duration::from_secs(sleep_duration.min(1)));
+                redraw(&render_status(), &mut last_line_count);
+                wait_until(deadline);
             }
         })
     }
+
+    /// Accept daemon connections on `path`, applying one `Command` per
+    /// connection and replying with its `Answer`
+    fn start_socket_thread(self: &Arc<Self>, path: PathBuf) -> io::Result<thread::JoinHandle<()>> {
+        let _ = std::fs::remove_file(&path);
+        let listener = UnixListener::bind(&path)?;
+        let timer = Arc::clone(self);
+
+        Ok(thread::spawn(move || {
+            for stream in listener.incoming() {
+                let Ok(mut stream) = stream else { continue };
+                let timer = Arc::clone(&timer);
+                thread::spawn(move || {
+                    if let Err(err) = handle_client(&timer, &mut stream) {
+                        eprintln!("rusty: client error: {}", err);
+                    }
+                });
+            }
+        }))
+    }
 }
 
-fn main() -> io::Result<()> {
-    let config = TimerConfig::default();
-    let timer = Timer::new(config);
+fn handle_client(timer: &Timer, stream: &mut UnixStream) -> io::Result<()> {
+    let command: Command = serde_json::from_reader(&mut *stream)?;
+    let answer = timer.apply(command);
+    serde_json::to_writer(&mut *stream, &answer)?;
+    stream.shutdown(Shutdown::Write)
+}
 
-    print!("{}{}", cursor::Hide, color::Fg(color::Green));
-    println!("Rusty timer started. Commands:");
-    println!("  +: Increase break interval by 5 minutes");
-    println!("  -: Decrease break interval by 5 minutes");
-    println!("  q: Quit");
-    io::stdout().flush()?;
+/// Read a line of input from the same key stream the main loop consumes,
+/// echoing characters back since the terminal is in raw mode
+fn read_line(
+    keys: &mut termion::input::Keys<io::StdinLock>,
+    stdout: &mut impl Write,
+    prompt: &str,
+) -> io::Result<String> {
+    let mut buffer = String::new();
+    write!(stdout, "\r{}{}", clear::CurrentLine, prompt)?;
+    stdout.flush()?;
 
-    // Start timer thread using Timer's method
-    let timer_handle = timer.start_timer_thread();
+    for key in keys.by_ref() {
+        match key? {
+            Key::Char('\n') => break,
+            Key::Char(c) => {
+                buffer.push(c);
+                write!(stdout, "{}", c)?;
+                stdout.flush()?;
+            }
+            Key::Backspace => {
+                buffer.pop();
+            }
+            Key::Ctrl('c') => return Ok(String::new()),
+            _ => {}
+        }
+    }
+    Ok(buffer)
+}
 
-    let stdin = io::stdin();
-    // RAII guard for terminal state
-    struct TerminalGuard {
-        stdout: termion::raw::RawTerminal<io::Stdout>,
+/// Set by `install_signal_handlers`'s handler when SIGINT/SIGTERM arrives.
+/// A plain `extern "C" fn` can't close over the `Timer`'s `Arc<AtomicBool>`,
+/// so the handler flips this process-wide flag instead; the main loop folds
+/// it into the same `should_exit` check it uses for the `q` key and `Quit`
+static SIGNAL_RECEIVED: AtomicBool = AtomicBool::new(false);
+
+extern "C" fn handle_signal(_signum: libc::c_int) {
+    SIGNAL_RECEIVED.store(true, Ordering::SeqCst);
+}
+
+/// Install SIGINT/SIGTERM handlers that request a clean shutdown instead of
+/// the default terminate-immediately action, which would otherwise leave the
+/// terminal in raw mode, cursor hidden, stuck on the alternate screen.
+/// `sa_flags` deliberately omits `SA_RESTART` so the blocking key read in the
+/// main loop is interrupted (returning `EINTR`) rather than silently resumed.
+fn install_signal_handlers() {
+    unsafe {
+        let mut action: libc::sigaction = std::mem::zeroed();
+        action.sa_sigaction = handle_signal as *const () as usize;
+        libc::sigemptyset(&mut action.sa_mask);
+        action.sa_flags = 0;
+        libc::sigaction(libc::SIGINT, &action, std::ptr::null_mut());
+        libc::sigaction(libc::SIGTERM, &action, std::ptr::null_mut());
     }
+}
 
-    impl TerminalGuard {
-        fn new() -> io::Result<Self> {
-            let mut stdout = io::stdout().into_raw_mode()?;
-            write!(stdout, "{}{}", cursor::Hide, color::Fg(color::Green))?;
-            Ok(Self { stdout })
-        }
+// RAII guard for terminal state: switches to the alternate screen so the
+// countdown never scrolls the user's scrollback, and guarantees both the
+// alternate screen and raw mode are left on drop -- normal exit and
+// signal-driven exit both end up running this same restore sequence
+struct TerminalGuard {
+    stdout: termion::screen::AlternateScreen<termion::raw::RawTerminal<io::Stdout>>,
+}
+
+impl TerminalGuard {
+    fn new() -> io::Result<Self> {
+        let mut stdout = io::stdout().into_raw_mode()?.into_alternate_screen()?;
+        write!(stdout, "{}{}", cursor::Hide, color::Fg(color::Green))?;
+        Ok(Self { stdout })
     }
+}
 
-    impl Drop for TerminalGuard {
-        fn drop(&mut self) {
-            write!(
-                self.stdout,
-                "{}{}{}",
-                clear::CurrentLine,
-                color::Fg(color::Reset),
-                cursor::Show
-            )
-            .unwrap();
-            self.stdout.flush().unwrap();
-        }
+impl Drop for TerminalGuard {
+    fn drop(&mut self) {
+        write!(
+            self.stdout,
+            "{}{}{}",
+            clear::CurrentLine,
+            color::Fg(color::Reset),
+            cursor::Show
+        )
+        .unwrap();
+        self.stdout.flush().unwrap();
+        // Leaving the alternate screen and raw mode happens automatically
+        // when `self.stdout`'s layers drop right after this function returns
     }
+}
+
+/// Run the daemon: owns the `Timer`, serves the keyboard front-end directly
+/// and the Unix-socket front-end in the background
+fn run_daemon() -> io::Result<()> {
+    install_signal_handlers();
+
+    let config = TimerConfig::default();
+    let timer = Arc::new(Timer::new(config));
 
+    let stdin = io::stdin();
+    let mut keys = stdin.lock().keys();
     let mut terminal = TerminalGuard::new()?;
 
-    for key in stdin.keys().flatten() {
-        match key {
-            Key::Char('+') | Key::Char('-') => {
-                timer.handle_interval_change(key, &mut terminal.stdout)?
+    write!(terminal.stdout, "Rusty timer daemon started. Commands:\r\n")?;
+    write!(terminal.stdout, "  a: Add a named timer\r\n")?;
+    write!(terminal.stdout, "  x: Remove a named timer\r\n")?;
+    write!(terminal.stdout, "  t: Toggle a named timer on/off\r\n")?;
+    write!(terminal.stdout, "  i: Change a named timer's interval\r\n")?;
+    write!(terminal.stdout, "  l: List timers\r\n")?;
+    write!(terminal.stdout, "  p: Pause/resume all timers\r\n")?;
+    write!(terminal.stdout, "  w: Start a Pomodoro session\r\n")?;
+    write!(terminal.stdout, "  k: Skip to the next Pomodoro phase\r\n")?;
+    write!(terminal.stdout, "  r: Reset the Pomodoro cycle count\r\n")?;
+    write!(terminal.stdout, "  q: Quit\r\n")?;
+    terminal.stdout.flush()?;
+
+    let timer_handle = timer.start_timer_thread();
+    let _socket_handle = timer.start_socket_thread(socket_path())?;
+
+    while !SIGNAL_RECEIVED.load(Ordering::SeqCst) {
+        let Some(result) = keys.next() else { break };
+        let key = match result {
+            Ok(key) => key,
+            Err(_) => continue,
+        };
+
+        let command = match key {
+            Key::Char('p') => Some(if timer.is_paused.load(Ordering::SeqCst) {
+                Command::Resume
+            } else {
+                Command::Pause
+            }),
+            Key::Char('a') => {
+                let name = read_line(&mut keys, &mut terminal.stdout, "Timer name: ")?;
+                let minutes = read_line(&mut keys, &mut terminal.stdout, "Every how many minutes: ")?;
+                match minutes.trim().parse::<u64>() {
+                    Ok(minutes) if !name.is_empty() => {
+                        Some(Command::Add { name, interval_secs: minutes * 60 })
+                    }
+                    _ => {
+                        write!(terminal.stdout, "\r{}Invalid timer", clear::CurrentLine)?;
+                        terminal.stdout.flush()?;
+                        None
+                    }
+                }
+            }
+            Key::Char('x') => {
+                let name = read_line(&mut keys, &mut terminal.stdout, "Timer name to remove: ")?;
+                Some(Command::Remove { name })
+            }
+            Key::Char('t') => {
+                let name = read_line(&mut keys, &mut terminal.stdout, "Timer name to toggle: ")?;
+                Some(Command::Toggle { name })
             }
+            Key::Char('i') => {
+                let name = read_line(&mut keys, &mut terminal.stdout, "Timer name: ")?;
+                let minutes = read_line(&mut keys, &mut terminal.stdout, "New interval (minutes): ")?;
+                match minutes.trim().parse::<u64>() {
+                    Ok(minutes) => Some(Command::SetInterval { name, interval_secs: minutes * 60 }),
+                    Err(_) => {
+                        write!(terminal.stdout, "\r{}Invalid interval", clear::CurrentLine)?;
+                        terminal.stdout.flush()?;
+                        None
+                    }
+                }
+            }
+            Key::Char('l') => Some(Command::List),
+            Key::Char('w') => Some(Command::PomodoroStart),
+            Key::Char('k') => Some(Command::PomodoroSkip),
+            Key::Char('r') => Some(Command::PomodoroReset),
             Key::Char('?') => {
-                write!(terminal.stdout, "\r{}Commands: + - q", clear::CurrentLine)?;
+                write!(
+                    terminal.stdout,
+                    "\r{}Commands: a x t i l p w k r q",
+                    clear::CurrentLine
+                )?;
                 terminal.stdout.flush()?;
+                None
             }
-            Key::Char('q') => {
-                timer.should_exit.store(true, Ordering::SeqCst);
-                break;
-            }
+            Key::Char('q') => Some(Command::Quit),
             _ => {
                 write!(
                     terminal.stdout,
@@ -204,6 +853,17 @@ fn main() -> io::Result<()> {
                     clear::CurrentLine
                 )?;
                 terminal.stdout.flush()?;
+                None
+            }
+        };
+
+        if let Some(command) = command {
+            let quitting = matches!(command, Command::Quit);
+            let answer = timer.apply(command);
+            write!(terminal.stdout, "\r{}{}", clear::CurrentLine, describe(&answer))?;
+            terminal.stdout.flush()?;
+            if quitting {
+                break;
             }
         }
     }
@@ -211,16 +871,61 @@ fn main() -> io::Result<()> {
     // Wait for the timer thread to finish
     timer.should_exit.store(true, Ordering::SeqCst);
     timer_handle.join().unwrap();
+    let _ = std::fs::remove_file(socket_path());
 
-    // Clean exit
-    write!(
-        terminal.stdout,
-        "\r{}{}{}",
-        clear::CurrentLine,
-        color::Fg(color::Reset),
-        cursor::Show
-    )?;
-    terminal.stdout.flush()?;
+    Ok(())
+}
+
+/// Parse a client-mode command line, e.g. `rusty client add stretch 20`
+fn parse_client_command(args: &[String]) -> Option<Command> {
+    let mut it = args.iter();
+    match it.next()?.as_str() {
+        "add" => Some(Command::Add {
+            name: it.next()?.clone(),
+            interval_secs: it.next()?.parse::<u64>().ok()? * 60,
+        }),
+        "remove" => Some(Command::Remove { name: it.next()?.clone() }),
+        "toggle" => Some(Command::Toggle { name: it.next()?.clone() }),
+        "pause" => Some(Command::Pause),
+        "resume" => Some(Command::Resume),
+        "set-interval" => Some(Command::SetInterval {
+            name: it.next()?.clone(),
+            interval_secs: it.next()?.parse::<u64>().ok()? * 60,
+        }),
+        "list" => Some(Command::List),
+        "pomodoro-start" => Some(Command::PomodoroStart),
+        "pomodoro-skip" => Some(Command::PomodoroSkip),
+        "pomodoro-reset" => Some(Command::PomodoroReset),
+        "quit" => Some(Command::Quit),
+        _ => None,
+    }
+}
 
+/// Run as a thin client: send one `Command` to the running daemon over its
+/// Unix socket and print the `Answer`
+fn run_client(args: Vec<String>) -> io::Result<()> {
+    let Some(command) = parse_client_command(&args) else {
+        eprintln!(
+            "Usage: rusty client <add|remove|toggle|set-interval> <name> [minutes]"
+        );
+        eprintln!("       rusty client <pause|resume|list|quit>");
+        eprintln!("       rusty client <pomodoro-start|pomodoro-skip|pomodoro-reset>");
+        return Ok(());
+    };
+
+    let mut stream = UnixStream::connect(socket_path())?;
+    serde_json::to_writer(&mut stream, &command)?;
+    stream.shutdown(Shutdown::Write)?;
+
+    let answer: Answer = serde_json::from_reader(&mut stream)?;
+    println!("{}", describe(&answer));
     Ok(())
 }
+
+fn main() -> io::Result<()> {
+    let mut args = std::env::args().skip(1);
+    match args.next().as_deref() {
+        Some("client") => run_client(args.collect()),
+        _ => run_daemon(),
+    }
+}